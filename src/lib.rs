@@ -1,11 +1,342 @@
 use gix::bstr::ByteSlice;
-use pyo3::exceptions::PyRuntimeError;
+use pyo3::exceptions::{PyFileNotFoundError, PyRuntimeError};
 use pyo3::{prelude::*, types::PyType};
+use std::num::NonZeroU32;
 use std::path::Path;
 
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use gix::progress::{Count, Id, MessageLevel, NestedProgress, Progress, Step, StepShared, Unit};
+
+/// A gix [`Progress`] implementation that forwards updates to a Python
+/// callable.
+///
+/// The callable is invoked as `callback(phase, current, max, bytes)` where
+/// `phase` is the current step's name, `current`/`max` are the raw step
+/// counts, and `bytes` is `current` when the step is measured in bytes
+/// (otherwise `None`). When no callback is supplied this behaves like
+/// [`gix::progress::Discard`].
+///
+/// gix drives byte throughput (pack receive) by incrementing the shared atomic
+/// handed out by [`Count::counter`], which never routes through [`emit`]. For
+/// byte phases we therefore spin up a [`BytePoller`] that samples the counter
+/// on a timer so the callback still sees throughput.
+struct PyProgress {
+    callback: Option<Py<PyAny>>,
+    name: String,
+    id: Id,
+    max: Option<Step>,
+    is_bytes: bool,
+    step: StepShared,
+    poller: Option<BytePoller>,
+}
+
+/// Background sampler that periodically pushes the shared counter value to the
+/// Python callback; stopped and joined on drop.
+struct BytePoller {
+    stop: Arc<AtomicBool>,
+    handle: Option<std::thread::JoinHandle<()>>,
+}
+
+impl Drop for BytePoller {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl PyProgress {
+    fn new(callback: Option<Py<PyAny>>) -> Self {
+        PyProgress {
+            callback,
+            name: String::new(),
+            id: gix::progress::UNKNOWN,
+            max: None,
+            is_bytes: false,
+            step: Arc::new(AtomicUsize::new(0)),
+            poller: None,
+        }
+    }
+
+    /// Start sampling the shared counter on a timer, forwarding `bytes` to the
+    /// callback until the poller stored in `self.poller` is dropped.
+    fn start_byte_poller(&mut self) {
+        let Some(callback) = self.callback.clone() else {
+            return;
+        };
+        let stop = Arc::new(AtomicBool::new(false));
+        let step = self.step.clone();
+        let name = self.name.clone();
+        let max = self.max;
+        let stop_signal = stop.clone();
+        let handle = std::thread::spawn(move || {
+            while !stop_signal.load(Ordering::Relaxed) {
+                let current = step.load(Ordering::Relaxed);
+                Python::with_gil(|py| {
+                    let _ = callback.call1(py, (name.clone(), current, max, Some(current)));
+                });
+                std::thread::sleep(Duration::from_millis(100));
+            }
+        });
+        self.poller = Some(BytePoller {
+            stop,
+            handle: Some(handle),
+        });
+    }
+
+    /// Re-acquire the GIL and push the current state to the Python callback.
+    fn emit(&self) {
+        let Some(callback) = &self.callback else {
+            return;
+        };
+        let current = self.step.load(Ordering::Relaxed);
+        let bytes = self.is_bytes.then_some(current);
+        Python::with_gil(|py| {
+            let _ = callback.call1(py, (self.name.clone(), current, self.max, bytes));
+        });
+    }
+}
+
+impl Count for PyProgress {
+    fn set(&self, step: Step) {
+        self.step.store(step, Ordering::Relaxed);
+        self.emit();
+    }
+
+    fn step(&self) -> Step {
+        self.step.load(Ordering::Relaxed)
+    }
+
+    fn inc_by(&self, step: Step) {
+        self.step.fetch_add(step, Ordering::Relaxed);
+        self.emit();
+    }
+
+    fn counter(&self) -> StepShared {
+        self.step.clone()
+    }
+}
+
+impl Progress for PyProgress {
+    fn init(&mut self, max: Option<Step>, unit: Option<Unit>) {
+        self.max = max;
+        self.is_bytes = matches!(&unit, Some(unit) if unit.as_ref().0 == "bytes");
+        self.step.store(0, Ordering::Relaxed);
+        self.emit();
+        // Byte phases only mutate the shared counter, so sample it on a timer.
+        if self.is_bytes {
+            self.start_byte_poller();
+        }
+    }
+
+    fn set_max(&mut self, max: Option<Step>) -> Option<Step> {
+        let prev = self.max.take();
+        self.max = max;
+        prev
+    }
+
+    fn set_name(&mut self, name: String) {
+        self.name = name;
+        self.emit();
+    }
+
+    fn name(&self) -> Option<String> {
+        Some(self.name.clone())
+    }
+
+    fn id(&self) -> Id {
+        self.id
+    }
+
+    fn message(&self, _level: MessageLevel, _message: String) {
+        self.emit();
+    }
+}
+
+impl NestedProgress for PyProgress {
+    type SubProgress = PyProgress;
+
+    fn add_child(&mut self, name: impl Into<String>) -> Self::SubProgress {
+        self.add_child_with_id(name, gix::progress::UNKNOWN)
+    }
+
+    fn add_child_with_id(&mut self, name: impl Into<String>, id: Id) -> Self::SubProgress {
+        PyProgress {
+            callback: self.callback.clone(),
+            name: name.into(),
+            id,
+            max: None,
+            is_bytes: false,
+            step: Arc::new(AtomicUsize::new(0)),
+            poller: None,
+        }
+    }
+}
+
+/// Decode a git path, which may contain arbitrary bytes, to a Python-friendly
+/// `String`, falling back to a lossy representation like [`Repo::branches`].
+fn decode_path(bytes: &gix::bstr::BStr) -> String {
+    match bytes.to_str() {
+        Ok(valid) => valid.to_owned(),
+        Err(_) => bytes.to_string(),
+    }
+}
+
+/// Return `true` if `location` names `path` or a file beneath it.
+fn path_under(location: &gix::bstr::BStr, path: &gix::bstr::BStr) -> bool {
+    if path.is_empty() {
+        return true;
+    }
+    if location == path {
+        return true;
+    }
+    location.len() > path.len()
+        && location.starts_with(path.as_ref())
+        && location[path.len()] == b'/'
+}
+
+/// A single commit, mirroring the fields GitPython surfaces on its `Commit`.
+#[pyclass]
+struct Commit {
+    #[pyo3(get)]
+    id: String,
+    #[pyo3(get)]
+    summary: String,
+    #[pyo3(get)]
+    message: String,
+    #[pyo3(get)]
+    author_name: String,
+    #[pyo3(get)]
+    author_email: String,
+    #[pyo3(get)]
+    committer_name: String,
+    #[pyo3(get)]
+    committer_email: String,
+    #[pyo3(get)]
+    time: i64,
+}
+
+/// A lazily-evaluated walk over commit history, as returned by [`Repo::log`].
+///
+/// The ancestry is walked one commit at a time and each commit is only decoded
+/// when the iterator is advanced, so large histories stream rather than being
+/// materialised in one go.
 #[pyclass(unsendable)]
+struct CommitLog {
+    repo: gix::Repository,
+    // A lazy ancestry walk driven one commit at a time. It owns its own object
+    // handle, so it is independent of the `repo` field's lifetime and nothing
+    // is walked until `__next__` pulls from it.
+    ids: Box<dyn Iterator<Item = Result<gix::ObjectId, String>>>,
+    remaining: Option<usize>,
+    paths: Option<Vec<gix::bstr::BString>>,
+}
+
+impl CommitLog {
+    /// Whether `commit` changes any of the filtered `paths` relative to its
+    /// first parent (or, for a root commit, adds any of them).
+    fn commit_touches(
+        &self,
+        commit: &gix::Commit<'_>,
+        paths: &[gix::bstr::BString],
+    ) -> PyResult<bool> {
+        let tree = commit
+            .tree()
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to load commit tree: {e}")))?;
+        let parent_tree = match commit.parent_ids().next() {
+            Some(parent) => parent
+                .object()
+                .and_then(|obj| obj.try_into_commit().map_err(Into::into))
+                .and_then(|parent| parent.tree())
+                .map_err(|e| {
+                    PyRuntimeError::new_err(format!("Failed to load parent tree: {e}"))
+                })?,
+            None => self.repo.empty_tree(),
+        };
+
+        let mut touched = false;
+        parent_tree
+            .changes()
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to diff trees: {e}")))?
+            .for_each_to_obtain_tree(&tree, |change| {
+                if paths.iter().any(|p| path_under(change.location, p.as_ref())) {
+                    touched = true;
+                }
+                Ok::<_, std::convert::Infallible>(gix::object::tree::diff::Action::Continue)
+            })
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to diff trees: {e}")))?;
+        Ok(touched)
+    }
+
+    /// Decode a single commit object into the Python-facing [`Commit`].
+    fn build(commit: &gix::Commit<'_>) -> PyResult<Commit> {
+        let id = commit.id().to_hex().to_string();
+        let summary = commit
+            .message()
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to read commit message: {e}")))?
+            .summary()
+            .to_string();
+        let decoded = commit
+            .decode()
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to decode commit: {e}")))?;
+        let author = decoded.author;
+        let committer = decoded.committer;
+        Ok(Commit {
+            id,
+            summary,
+            message: decoded.message.to_string(),
+            author_name: author.name.to_string(),
+            author_email: author.email.to_string(),
+            committer_name: committer.name.to_string(),
+            committer_email: committer.email.to_string(),
+            time: committer.seconds(),
+        })
+    }
+}
+
+#[pymethods]
+impl CommitLog {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> PyResult<Option<Commit>> {
+        loop {
+            if self.remaining == Some(0) {
+                return Ok(None);
+            }
+            let Some(next) = self.ids.next() else {
+                return Ok(None);
+            };
+            let oid = next
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to traverse commit: {e}")))?;
+            let commit = self
+                .repo
+                .find_object(oid)
+                .and_then(|obj| obj.try_into_commit().map_err(Into::into))
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to load commit: {e}")))?;
+
+            if let Some(paths) = &self.paths {
+                if !self.commit_touches(&commit, paths)? {
+                    continue;
+                }
+            }
+
+            if let Some(remaining) = &mut self.remaining {
+                *remaining -= 1;
+            }
+            return Ok(Some(CommitLog::build(&commit)?));
+        }
+    }
+}
+
+#[pyclass]
 struct Repo {
-    inner: gix::Repository,
+    inner: gix::ThreadSafeRepository,
 }
 
 #[pymethods]
@@ -13,7 +344,8 @@ impl Repo {
     /// The path to the `.git` directory of the repository.
     #[getter]
     fn git_dir(&self, py: Python) -> PyResult<PyObject> {
-        let git_dir_path = self.inner.git_dir();
+        let repo = self.inner.to_thread_local();
+        let git_dir_path = repo.git_dir();
         let pathlib = py.import("pathlib")?;
         let path_class = pathlib.getattr("Path")?;
         let path_obj = path_class.call1((git_dir_path.as_os_str(),))?;
@@ -21,13 +353,39 @@ impl Repo {
     }
 
     /// Clone a git repository from the given URL into the specified path.
+    ///
+    /// `depth` requests a shallow clone truncated to that many commits at the
+    /// remote; `shallow_since` and `shallow_exclude` are the
+    /// cutoff-based alternatives (mutually exclusive with `depth` and each
+    /// other). `single_branch`/`branch` restrict the fetch to a single named
+    /// branch and check it out, and `no_tags` suppresses tag following.
     #[classmethod]
-    #[pyo3(signature = (url, to_path, bare=false))]
+    #[pyo3(signature = (
+        url,
+        to_path,
+        bare=false,
+        depth=None,
+        shallow_since=None,
+        shallow_exclude=None,
+        single_branch=false,
+        no_tags=false,
+        branch=None,
+        progress=None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
     fn clone_from(
         _cls: &Bound<'_, PyType>,
+        py: Python<'_>,
         url: &str,
         to_path: &str,
         bare: bool,
+        depth: Option<u32>,
+        shallow_since: Option<&str>,
+        shallow_exclude: Option<Vec<String>>,
+        single_branch: bool,
+        no_tags: bool,
+        branch: Option<&str>,
+        progress: Option<Py<PyAny>>,
     ) -> PyResult<Self> {
         let target_path = Path::new(to_path);
 
@@ -47,27 +405,103 @@ impl Repo {
         )
         .map_err(|e| PyRuntimeError::new_err(format!("Failed to prepare clone: {}", e)))?;
 
-        let (mut prepare_checkout, _outcome) = prepare_clone
-            .fetch_then_checkout(gix::progress::Discard, &gix::interrupt::IS_INTERRUPTED)
-            .map_err(|e| PyRuntimeError::new_err(format!("Failed to fetch repository: {}", e)))?;
-
-        if bare {
-            let repo = prepare_checkout.persist();
-            Ok(Repo { inner: repo })
+        // Translate the depth / since / exclude options into a single
+        // `Shallow` directive. These are mutually exclusive; `depth` wins,
+        // then `shallow_since`, then `shallow_exclude`.
+        let shallow = if let Some(depth) = depth {
+            let depth = NonZeroU32::new(depth)
+                .ok_or_else(|| PyRuntimeError::new_err("depth must be greater than zero"))?;
+            gix::remote::fetch::Shallow::DepthAtRemote(depth)
+        } else if let Some(since) = shallow_since {
+            let cutoff = gix::date::parse(since, Some(std::time::SystemTime::now()))
+                .map_err(|e| PyRuntimeError::new_err(format!("Invalid shallow_since date: {e}")))?;
+            gix::remote::fetch::Shallow::Since { cutoff }
+        } else if let Some(exclude) = shallow_exclude {
+            gix::remote::fetch::Shallow::Exclude {
+                remote_refs: exclude.into_iter().map(Into::into).collect(),
+                since_cutoff: None,
+            }
         } else {
-            let (repo, _checkout_outcome) = prepare_checkout
-                .main_worktree(gix::progress::Discard, &gix::interrupt::IS_INTERRUPTED)
-                .map_err(|e| {
-                    PyRuntimeError::new_err(format!("Failed to checkout worktree: {}", e))
-                })?;
+            gix::remote::fetch::Shallow::NoChange
+        };
+        prepare_clone = prepare_clone.with_shallow(shallow);
+
+        // Select a specific branch to fetch and check out, if requested.
+        if let Some(branch) = branch {
+            prepare_clone = prepare_clone
+                .with_ref_name(Some(branch))
+                .map_err(|e| PyRuntimeError::new_err(format!("Invalid branch name: {e}")))?;
+        }
 
-            Ok(Repo { inner: repo })
+        // `single_branch` needs a branch to narrow to; restricting to the
+        // remote's HEAD branch (git's default) isn't supported here, so reject
+        // the combination rather than silently doing a full clone.
+        if single_branch && branch.is_none() {
+            return Err(PyRuntimeError::new_err(
+                "single_branch requires an explicit branch",
+            ));
         }
+
+        // Narrow the remote's refspecs / tag behaviour before fetching. The
+        // closure is `'static`, so capture owned copies of what it needs.
+        if no_tags || single_branch {
+            let branch = branch.map(ToOwned::to_owned);
+            prepare_clone = prepare_clone.configure_remote(move |mut remote| {
+                if no_tags {
+                    remote = remote.with_fetch_tags(gix::remote::fetch::Tags::None);
+                }
+                if single_branch {
+                    if let Some(branch) = &branch {
+                        // Derive the remote name instead of assuming `origin`.
+                        let remote_name = remote
+                            .name()
+                            .map(|name| name.as_bstr().to_string())
+                            .unwrap_or_else(|| "origin".to_owned());
+                        remote = remote.with_refspecs(
+                            Some(
+                                format!(
+                                    "+refs/heads/{branch}:refs/remotes/{remote_name}/{branch}"
+                                )
+                                .as_str(),
+                            ),
+                            gix::remote::Direction::Fetch,
+                        )?;
+                    }
+                }
+                Ok(remote)
+            });
+        }
+
+        // Build the progress adapters while the GIL is held, then run the
+        // blocking transfer with it released so other Python threads proceed.
+        let fetch_progress = PyProgress::new(progress.clone());
+        let checkout_progress = PyProgress::new(progress);
+        let repo = py
+            .allow_threads(move || -> Result<gix::Repository, String> {
+                let (mut prepare_checkout, _outcome) = prepare_clone
+                    .fetch_then_checkout(fetch_progress, &gix::interrupt::IS_INTERRUPTED)
+                    .map_err(|e| format!("Failed to fetch repository: {e}"))?;
+
+                if bare {
+                    Ok(prepare_checkout.persist())
+                } else {
+                    let (repo, _checkout_outcome) = prepare_checkout
+                        .main_worktree(checkout_progress, &gix::interrupt::IS_INTERRUPTED)
+                        .map_err(|e| format!("Failed to checkout worktree: {e}"))?;
+                    Ok(repo)
+                }
+            })
+            .map_err(PyRuntimeError::new_err)?;
+
+        Ok(Repo {
+            inner: repo.into_sync(),
+        })
     }
 
     /// Return the names of all local branches in the repository.
     fn branches(&self) -> PyResult<Vec<String>> {
-        let platform = self.inner.references().map_err(|err| {
+        let repo = self.inner.to_thread_local();
+        let platform = repo.references().map_err(|err| {
             PyRuntimeError::new_err(format!("Failed to access references: {err}"))
         })?;
 
@@ -97,11 +531,518 @@ impl Repo {
 
         Ok(branches)
     }
+
+    /// Return the status of each changed path in the working tree.
+    ///
+    /// Returns a list of `(path, status)` tuples where `status` is one of `modified`,
+    /// `added`, `deleted`, `renamed`, or `untracked`, combining the
+    /// index-vs-worktree and tree-vs-index comparisons. The scan runs with the
+    /// GIL released, since it is expensive on large repositories.
+    fn status(&self, py: Python) -> PyResult<Vec<(String, String)>> {
+        use gix::bstr::BString;
+
+        let entries = py
+            .allow_threads(|| -> Result<Vec<(BString, &'static str)>, String> {
+                let repo = self.inner.to_thread_local();
+                let platform = repo
+                    .status(gix::progress::Discard)
+                    .map_err(|e| format!("Failed to prepare status: {e}"))?
+                    // Emit individual untracked files rather than collapsing
+                    // whole untracked directories into a single entry.
+                    .untracked_files(gix::status::UntrackedFiles::Files);
+
+                let mut out = Vec::new();
+                let iter = platform
+                    .into_iter(None)
+                    .map_err(|e| format!("Failed to compute status: {e}"))?;
+                for item in iter {
+                    let item = item.map_err(|e| format!("Failed to read status entry: {e}"))?;
+                    let (path, code): (BString, &'static str) = match item {
+                        gix::status::Item::IndexWorktree(change) => {
+                            use gix::status::index_worktree::Item;
+                            match change {
+                                Item::Modification { rela_path, .. } => (rela_path, "modified"),
+                                Item::DirectoryContents { entry, .. } => {
+                                    (entry.rela_path, "untracked")
+                                }
+                                Item::Rewrite { dirwalk_entry, .. } => {
+                                    (dirwalk_entry.rela_path, "renamed")
+                                }
+                            }
+                        }
+                        gix::status::Item::TreeIndex(change) => {
+                            use gix::diff::index::Change;
+                            match change {
+                                Change::Addition { location, .. } => {
+                                    (location.into_owned(), "added")
+                                }
+                                Change::Deletion { location, .. } => {
+                                    (location.into_owned(), "deleted")
+                                }
+                                Change::Modification { location, .. } => {
+                                    (location.into_owned(), "modified")
+                                }
+                                Change::Rewrite { location, .. } => {
+                                    (location.into_owned(), "renamed")
+                                }
+                            }
+                        }
+                    };
+                    out.push((path, code));
+                }
+                Ok(out)
+            })
+            .map_err(PyRuntimeError::new_err)?;
+
+        Ok(entries
+            .into_iter()
+            .map(|(path, code)| (decode_path(path.as_ref()), code.to_owned()))
+            .collect())
+    }
+
+    /// Walk commit history starting at `rev`, newest first.
+    ///
+    /// Returns an iterator of [`Commit`]s. The history is walked lazily as the
+    /// iterator is advanced — nothing is materialised up front — so capping
+    /// with `max_count` or breaking out early stays cheap on large repos.
+    /// `paths` restricts the walk to commits that change one of the given paths
+    /// (computed via a per-commit tree diff against the first parent).
+    #[pyo3(signature = (rev="HEAD", max_count=None, paths=None))]
+    fn log(
+        &self,
+        rev: &str,
+        max_count: Option<usize>,
+        paths: Option<Vec<String>>,
+    ) -> PyResult<CommitLog> {
+        let repo = self.inner.to_thread_local();
+        let start = repo
+            .rev_parse_single(rev)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to resolve revision: {e}")))?;
+
+        // Drive an ancestry walk over an owned object handle so it streams
+        // independently of the repository borrow rather than collecting every
+        // ancestor id up front.
+        let walk = gix::traverse::commit::Simple::new(Some(start.detach()), repo.objects.clone())
+            .sorting(gix::traverse::commit::simple::Sorting::ByCommitTimeNewestFirst)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to walk history: {e}")))?;
+        let ids = Box::new(walk.map(|info| info.map(|info| info.id).map_err(|e| e.to_string())));
+
+        Ok(CommitLog {
+            repo,
+            ids,
+            remaining: max_count,
+            paths: paths.map(|paths| paths.into_iter().map(Into::into).collect()),
+        })
+    }
+
+    /// List the entries of the tree at `rev`, optionally descending into `path`.
+    ///
+    /// Each entry is `(name, mode, id, kind)`, where `mode` is the octal file
+    /// mode, `id` is the hex object id, and `kind` is `blob`, `tree`, or
+    /// `commit`. A `path` that does not exist raises `FileNotFoundError`.
+    #[pyo3(signature = (rev="HEAD", path=""))]
+    fn ls_tree(
+        &self,
+        rev: &str,
+        path: &str,
+    ) -> PyResult<Vec<(String, String, String, String)>> {
+        use gix::object::tree::EntryKind;
+
+        let repo = self.inner.to_thread_local();
+        let tree = self.tree_at(&repo, rev)?;
+
+        let tree = if path.is_empty() {
+            tree
+        } else {
+            let entry = tree
+                .lookup_entry_by_path(Path::new(path))
+                .map_err(|e| PyRuntimeError::new_err(format!("Failed to look up path: {e}")))?
+                .ok_or_else(|| {
+                    PyFileNotFoundError::new_err(format!("path not found: {path}"))
+                })?;
+            entry
+                .object()
+                .and_then(|obj| obj.peel_to_tree().map_err(Into::into))
+                .map_err(|e| PyRuntimeError::new_err(format!("path is not a tree: {e}")))?
+        };
+
+        let mut entries = Vec::new();
+        for entry in tree.iter() {
+            let entry =
+                entry.map_err(|e| PyRuntimeError::new_err(format!("Failed to read entry: {e}")))?;
+            let mode = entry.mode();
+            let kind = match mode.kind() {
+                EntryKind::Tree => "tree",
+                EntryKind::Commit => "commit",
+                _ => "blob",
+            };
+            entries.push((
+                decode_path(entry.filename()),
+                format!("{:06o}", mode.value()),
+                entry.object_id().to_hex().to_string(),
+                kind.to_owned(),
+            ));
+        }
+        Ok(entries)
+    }
+
+    /// Return the raw contents of the blob at `path` in revision `rev`.
+    ///
+    /// Raises `FileNotFoundError` if the path does not exist, or a runtime
+    /// error if it does not resolve to a blob.
+    #[pyo3(signature = (rev, path))]
+    fn read_blob<'py>(
+        &self,
+        py: Python<'py>,
+        rev: &str,
+        path: &str,
+    ) -> PyResult<Bound<'py, pyo3::types::PyBytes>> {
+        let repo = self.inner.to_thread_local();
+        let tree = self.tree_at(&repo, rev)?;
+
+        let entry = tree
+            .lookup_entry_by_path(Path::new(path))
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to look up path: {e}")))?
+            .ok_or_else(|| PyFileNotFoundError::new_err(format!("path not found: {path}")))?;
+
+        let blob = entry
+            .object()
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to load object: {e}")))?
+            .try_into_blob()
+            .map_err(|_| PyRuntimeError::new_err(format!("path is not a blob: {path}")))?;
+
+        Ok(pyo3::types::PyBytes::new(py, &blob.data))
+    }
+
+    /// Fetch from `remote`, returning a summary of the refs that were updated.
+    ///
+    /// Each summary entry is `(name, old_id, new_id, update_kind)`. `refspecs`
+    /// overrides the remote's configured fetch refspecs. To stay within the
+    /// limits of servers hosting enormous ref sets, the effective refspecs are
+    /// split into bounded batches that are fetched sequentially, with the
+    /// results accumulated.
+    #[pyo3(signature = (remote="origin", refspecs=None))]
+    fn fetch(
+        &self,
+        py: Python<'_>,
+        remote: &str,
+        refspecs: Option<Vec<String>>,
+    ) -> PyResult<Vec<RefUpdate>> {
+        let remote = remote.to_owned();
+        py.allow_threads(move || self.fetch_batched(&remote, refspecs))
+            .map_err(PyRuntimeError::new_err)
+    }
+
+    /// Fetch from `remote` and fast-forward the current branch to its upstream.
+    ///
+    /// Returns the same summary as [`Repo::fetch`]. On a non-bare repository the
+    /// index and working tree are updated to the new commit — files deleted
+    /// upstream are removed — so the checkout stays consistent and a subsequent
+    /// [`Repo::status`] is accurate. To avoid clobbering local work, `pull`
+    /// refuses when there are tracked modifications or staged changes (untracked
+    /// files, which a fast-forward never touches, are ignored). Raises a runtime
+    /// error if HEAD is detached, has no configured upstream, or cannot be
+    /// fast-forwarded.
+    #[pyo3(signature = (remote="origin"))]
+    fn pull(&self, py: Python<'_>, remote: &str) -> PyResult<Vec<RefUpdate>> {
+        let updates = self.fetch(py, remote, None)?;
+
+        let repo = self.inner.to_thread_local();
+
+        // Refuse to fast-forward over local changes on a non-bare repo, but do
+        // not let stray untracked files block the pull the way git wouldn't.
+        if repo.work_dir().is_some()
+            && self
+                .status(py)?
+                .iter()
+                .any(|(_, code)| code != "untracked")
+        {
+            return Err(PyRuntimeError::new_err(
+                "cannot pull: the working tree has uncommitted changes",
+            ));
+        }
+
+        let head = repo
+            .head()
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to read HEAD: {e}")))?;
+        let branch = head
+            .referent_name()
+            .ok_or_else(|| PyRuntimeError::new_err("cannot pull with a detached HEAD"))?
+            .to_owned();
+
+        let tracking = repo
+            .branch_remote_tracking_ref_name(branch.as_ref(), gix::remote::Direction::Fetch)
+            .ok_or_else(|| PyRuntimeError::new_err("current branch has no configured upstream"))?
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to resolve upstream: {e}")))?;
+
+        let target = repo
+            .find_reference(tracking.as_ref())
+            .and_then(|mut r| r.peel_to_id_in_place())
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to resolve upstream ref: {e}")))?
+            .detach();
+
+        let current = head.id().map(|id| id.detach());
+
+        // Only fast-forward: the current commit must be an ancestor of the
+        // upstream tip.
+        if let Some(current) = current {
+            if current != target {
+                let is_ancestor = repo
+                    .rev_walk(Some(target))
+                    .all()
+                    .map_err(|e| {
+                        PyRuntimeError::new_err(format!("Failed to walk upstream: {e}"))
+                    })?
+                    .filter_map(Result::ok)
+                    .any(|info| info.id == current);
+                if !is_ancestor {
+                    return Err(PyRuntimeError::new_err(
+                        "cannot fast-forward: branches have diverged",
+                    ));
+                }
+            }
+        }
+
+        // Update the index and working tree *before* moving the ref, so a
+        // checkout failure leaves the branch where it was rather than pointing
+        // it at a commit the tree doesn't reflect (files already removed below
+        // stay removed). Bare repos skip this.
+        if let Some(work_dir) = repo.work_dir().map(ToOwned::to_owned) {
+            py.allow_threads(|| -> Result<(), String> {
+                let to_tree = repo
+                    .find_object(target)
+                    .and_then(|obj| obj.peel_to_tree().map_err(Into::into))
+                    .map_err(|e| format!("Failed to resolve target tree: {e}"))?;
+
+                // Remove paths that were deleted or renamed away upstream; the
+                // checkout below only materialises what the target tree still
+                // contains.
+                if let Some(current) = current {
+                    let from_tree = repo
+                        .find_object(current)
+                        .and_then(|obj| obj.peel_to_tree().map_err(Into::into))
+                        .map_err(|e| format!("Failed to resolve current tree: {e}"))?;
+                    from_tree
+                        .changes()
+                        .map_err(|e| format!("Failed to diff trees: {e}"))?
+                        .for_each_to_obtain_tree(&to_tree, |change| {
+                            use gix::object::tree::diff::Change;
+                            let removed = match &change {
+                                Change::Deletion { location, .. } => Some(*location),
+                                Change::Rewrite {
+                                    source_location, ..
+                                } => Some(*source_location),
+                                _ => None,
+                            };
+                            if let Some(location) = removed {
+                                let path = work_dir.join(gix::path::from_bstr(location).as_ref());
+                                let _ = std::fs::remove_file(path);
+                            }
+                            Ok::<_, std::convert::Infallible>(
+                                gix::object::tree::diff::Action::Continue,
+                            )
+                        })
+                        .map_err(|e| format!("Failed to diff trees: {e}"))?;
+                }
+
+                // Materialise the target tree, overwriting the files a
+                // fast-forward leaves already present.
+                let mut index = repo
+                    .index_from_tree(&to_tree.id().detach())
+                    .map_err(|e| format!("Failed to build index from tree: {e}"))?;
+                let options = gix::worktree::state::checkout::Options {
+                    overwrite_existing: true,
+                    ..Default::default()
+                };
+                gix::worktree::state::checkout(
+                    &mut index,
+                    work_dir,
+                    repo.objects.clone(),
+                    &gix::progress::Discard,
+                    &gix::progress::Discard,
+                    &gix::interrupt::IS_INTERRUPTED,
+                    options,
+                )
+                .map_err(|e| format!("Failed to update working tree: {e}"))?;
+                index
+                    .write(Default::default())
+                    .map_err(|e| format!("Failed to write index: {e}"))?;
+                Ok(())
+            })
+            .map_err(PyRuntimeError::new_err)?;
+        }
+
+        // Advance the branch only after the worktree update succeeded.
+        repo.reference(
+            branch.as_ref(),
+            target,
+            gix::refs::transaction::PreviousValue::Any,
+            "pull: fast-forward",
+        )
+        .map_err(|e| PyRuntimeError::new_err(format!("Failed to update branch: {e}")))?;
+
+        Ok(updates)
+    }
+}
+
+/// A single ref updated by a fetch: `(name, old_id, new_id, update_kind)`.
+///
+/// `update_kind` is one of the stable strings `new`, `fast-forward`, `forced`,
+/// `unchanged`, `skipped`, or `rejected`.
+type RefUpdate = (String, Option<String>, Option<String>, String);
+
+impl Repo {
+    /// Maximum number of refspecs fed to a single fetch; larger sets are split
+    /// into sequential batches to avoid overwhelming servers on huge repos.
+    const REFSPEC_BATCH_SIZE: usize = 50;
+
+    /// Fetch `remote` in bounded refspec batches, accumulating the ref updates.
+    ///
+    /// Runs with the GIL released; errors are returned as strings so the caller
+    /// can map them into `PyRuntimeError`.
+    fn fetch_batched(
+        &self,
+        remote: &str,
+        refspecs: Option<Vec<String>>,
+    ) -> Result<Vec<RefUpdate>, String> {
+        let repo = self.inner.to_thread_local();
+        let base = repo
+            .find_remote(remote)
+            .map_err(|e| format!("Failed to find remote {remote}: {e}"))?;
+
+        let url = base
+            .url(gix::remote::Direction::Fetch)
+            .ok_or_else(|| format!("remote {remote} has no fetch URL"))?
+            .to_owned();
+
+        // Collect the effective refspecs, falling back to the remote's
+        // configured ones when none were supplied.
+        let effective: Vec<String> = match refspecs {
+            Some(specs) => specs,
+            None => base
+                .refspecs(gix::remote::Direction::Fetch)
+                .iter()
+                .map(|spec| spec.to_ref().to_bstring().to_string())
+                .collect(),
+        };
+        drop(base);
+
+        let mut updates = Vec::new();
+        for batch in effective.chunks(Self::REFSPEC_BATCH_SIZE) {
+            let remote = repo
+                .remote_at(url.clone())
+                .and_then(|remote| {
+                    remote.with_refspecs(
+                        batch.iter().map(String::as_str),
+                        gix::remote::Direction::Fetch,
+                    )
+                })
+                .map_err(|e| format!("Failed to configure fetch: {e}"))?;
+
+            let outcome = remote
+                .connect(gix::remote::Direction::Fetch)
+                .map_err(|e| format!("Failed to connect to remote: {e}"))?
+                .prepare_fetch(gix::progress::Discard, Default::default())
+                .map_err(|e| format!("Failed to prepare fetch: {e}"))?
+                .receive(gix::progress::Discard, &gix::interrupt::IS_INTERRUPTED)
+                .map_err(|e| format!("Failed to fetch: {e}"))?;
+
+            if let gix::remote::fetch::Status::Change { update_refs, .. } = outcome.status {
+                use gix::refs::transaction::{Change, PreviousValue};
+
+                // A fetch emits an `Update` per candidate ref, including no-op
+                // and rejected ones that produce no `RefEdit`. Correlate each
+                // update with its edit via `edit_index` rather than positional
+                // zip, which would truncate and misalign as soon as one update
+                // has no edit.
+                for update in &update_refs.updates {
+                    let Some(edit) = update_refs.edits.get(update.edit_index) else {
+                        continue;
+                    };
+                    let name = edit.name.as_bstr().to_string();
+                    let (old, new) = match &edit.change {
+                        Change::Update { expected, new, .. } => {
+                            let old = match expected {
+                                PreviousValue::ExistingMustMatch(target)
+                                | PreviousValue::MustExistAndMatch(target) => {
+                                    target.try_id().map(|id| id.to_hex().to_string())
+                                }
+                                _ => None,
+                            };
+                            (old, new.try_id().map(|id| id.to_hex().to_string()))
+                        }
+                        _ => (None, None),
+                    };
+                    // Map gix's internal update mode onto stable, documented
+                    // strings rather than leaking its `Debug` rendering.
+                    use gix::remote::fetch::refs::update::Mode;
+                    let kind = match &update.mode {
+                        Mode::New => "new",
+                        Mode::FastForward => "fast-forward",
+                        Mode::Forced => "forced",
+                        Mode::NoChangeNeeded => "unchanged",
+                        Mode::ImplicitTagNotSentByRemote => "skipped",
+                        _ => "rejected",
+                    };
+                    updates.push((name, old, new, kind.to_owned()));
+                }
+            }
+        }
+
+        Ok(updates)
+    }
+
+    /// Resolve `rev` and peel it to its tree.
+    fn tree_at<'repo>(
+        &self,
+        repo: &'repo gix::Repository,
+        rev: &str,
+    ) -> PyResult<gix::Tree<'repo>> {
+        repo.rev_parse_single(rev)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to resolve revision: {e}")))?
+            .object()
+            .and_then(|obj| obj.peel_to_tree().map_err(Into::into))
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to peel to tree: {e}")))
+    }
 }
 
 /// A pure git Python module implemented in Rust.
 #[pymodule]
 fn gitpure(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Repo>()?;
+    m.add_class::<Commit>()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gix::bstr::ByteSlice;
+
+    #[test]
+    fn path_under_matches_the_path_filter_semantics() {
+        // An empty filter matches everything, mirroring `log(paths=[""])`.
+        assert!(path_under(b"anything/at/all".as_bstr(), b"".as_bstr()));
+
+        // Exact matches and files nested beneath a directory are included.
+        assert!(path_under(b"src/lib.rs".as_bstr(), b"src/lib.rs".as_bstr()));
+        assert!(path_under(b"src/lib.rs".as_bstr(), b"src".as_bstr()));
+        assert!(path_under(b"src/inner/mod.rs".as_bstr(), b"src".as_bstr()));
+
+        // A shared prefix that does not fall on a path boundary is excluded,
+        // as is a location shorter than the filter.
+        assert!(!path_under(b"srctest/lib.rs".as_bstr(), b"src".as_bstr()));
+        assert!(!path_under(b"src".as_bstr(), b"src/lib.rs".as_bstr()));
+        assert!(!path_under(b"other/lib.rs".as_bstr(), b"src".as_bstr()));
+    }
+
+    #[test]
+    fn decode_path_falls_back_to_lossy_for_invalid_utf8() {
+        assert_eq!(decode_path(b"normal/path".as_bstr()), "normal/path");
+
+        // Invalid UTF-8 must not panic and should still yield a string.
+        let decoded = decode_path(b"bad/\xff/path".as_bstr());
+        assert!(decoded.contains("bad/"));
+        assert!(decoded.contains("/path"));
+    }
+}